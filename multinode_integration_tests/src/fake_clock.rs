@@ -0,0 +1,144 @@
+// Copyright (c) 2017-2019, Substratum LLC (https://substratum.net) and/or its affiliates. All rights reserved.
+
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// A controllable clock that stands in for `std::time::Instant::now()`, scoped to wherever its
+/// owner chooses to share it rather than to a single OS thread. A test constructs one
+/// `FakeClock` and clones it into every thread that needs to observe the same fake time — for
+/// example a mock Node's `wait_for_gossip` loop and the thread that calls `advance` to force it
+/// to time out. Clones share the underlying instant, so a write from one thread is immediately
+/// visible to another; distinct `FakeClock`s are completely independent, so tests running in
+/// parallel don't interfere with one another by way of this clock. Real Nodes never construct a
+/// `FakeClock`, so they're unaffected.
+#[derive(Clone)]
+pub struct FakeClock {
+    instant: Arc<Mutex<Option<Instant>>>,
+}
+
+/// Reverts the `FakeClock` it was returned from to the real system clock when dropped, so a test
+/// that panics partway through doesn't leave stale fake time behind for whatever runs next.
+#[must_use]
+pub struct FakeClockGuard {
+    clock: FakeClock,
+}
+
+impl Drop for FakeClockGuard {
+    fn drop(&mut self) {
+        self.clock.reset();
+    }
+}
+
+impl Default for FakeClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FakeClock {
+    pub fn new() -> FakeClock {
+        FakeClock {
+            instant: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    /// Returns the fake time if one has been established with `set` or `advance`, falling back
+    /// to the real `Instant::now()` otherwise.
+    pub fn now(&self) -> Instant {
+        self.instant
+            .lock()
+            .expect("fake clock is poisoned")
+            .unwrap_or_else(Instant::now)
+    }
+
+    /// Pins the fake time to a specific `Instant` until the returned guard is dropped.
+    pub fn set(&self, instant: Instant) -> FakeClockGuard {
+        *self.instant.lock().expect("fake clock is poisoned") = Some(instant);
+        FakeClockGuard {
+            clock: self.clone(),
+        }
+    }
+
+    /// Advances the fake time by `duration`, establishing it from the real clock first if it
+    /// hasn't been set yet, until the returned guard is dropped.
+    pub fn advance(&self, duration: Duration) -> FakeClockGuard {
+        let mut instant = self.instant.lock().expect("fake clock is poisoned");
+        let base = instant.unwrap_or_else(Instant::now);
+        *instant = Some(base + duration);
+        drop(instant);
+        FakeClockGuard {
+            clock: self.clone(),
+        }
+    }
+
+    fn reset(&self) {
+        *self.instant.lock().expect("fake clock is poisoned") = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+
+    #[test]
+    fn now_defaults_to_the_real_clock_when_never_set() {
+        let clock = FakeClock::new();
+        let before = Instant::now();
+
+        let fake_now = clock.now();
+
+        let after = Instant::now();
+        assert!(fake_now >= before && fake_now <= after);
+    }
+
+    #[test]
+    fn set_pins_now_to_the_given_instant() {
+        let clock = FakeClock::new();
+        let pinned = Instant::now() - Duration::from_secs(60);
+
+        let _guard = clock.set(pinned);
+
+        assert_eq!(clock.now(), pinned);
+    }
+
+    #[test]
+    fn advance_composes_from_a_previously_set_time() {
+        let clock = FakeClock::new();
+        let base = Instant::now() - Duration::from_secs(60);
+        let _first_guard = clock.set(base);
+
+        let _guard = clock.advance(Duration::from_secs(5));
+        let _guard = clock.advance(Duration::from_secs(5));
+
+        assert_eq!(clock.now(), base + Duration::from_secs(10));
+    }
+
+    #[test]
+    fn dropping_the_guard_reverts_to_the_real_clock() {
+        let clock = FakeClock::new();
+
+        {
+            let _guard = clock.set(Instant::now() - Duration::from_secs(3600));
+            assert!(clock.now() < Instant::now() - Duration::from_secs(1800));
+        }
+
+        let before = Instant::now();
+        let fake_now = clock.now();
+        let after = Instant::now();
+        assert!(fake_now >= before && fake_now <= after);
+    }
+
+    #[test]
+    fn clones_share_state_across_threads() {
+        let clock = FakeClock::new();
+        let base = Instant::now();
+        let _guard = clock.set(base);
+        let advancer = clock.clone();
+
+        let handle = thread::spawn(move || advancer.advance(Duration::from_secs(30)));
+        let _advance_guard = handle.join().expect("advancer thread panicked");
+
+        assert_eq!(clock.now(), base + Duration::from_secs(30));
+    }
+}
@@ -0,0 +1,9 @@
+// Copyright (c) 2017-2019, Substratum LLC (https://substratum.net) and/or its affiliates. All rights reserved.
+
+pub mod fake_clock;
+pub mod multinode_gossip;
+pub mod neighborhood_constructor;
+pub mod substratum_mock_node;
+pub mod substratum_node;
+pub mod substratum_node_cluster;
+pub mod substratum_real_node;
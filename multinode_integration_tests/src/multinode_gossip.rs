@@ -0,0 +1,81 @@
+// Copyright (c) 2017-2019, Substratum LLC (https://substratum.net) and/or its affiliates. All rights reserved.
+
+use crate::substratum_mock_node::SubstratumMockNode;
+use crate::substratum_node::SubstratumNode;
+use node_lib::sub_lib::cryptde::PublicKey;
+
+/// A Gossip message that's already been serialized and is ready to hand to
+/// `SubstratumMockNode::transmit_multinode_gossip`.
+#[derive(Clone)]
+pub struct Standard {
+    bytes: Vec<u8>,
+}
+
+impl From<&Vec<u8>> for Standard {
+    fn from(bytes: &Vec<u8>) -> Self {
+        Standard {
+            bytes: bytes.clone(),
+        }
+    }
+}
+
+impl From<Standard> for Vec<u8> {
+    fn from(standard: Standard) -> Self {
+        standard.bytes
+    }
+}
+
+struct StandardNode {
+    public_key: PublicKey,
+    version: u32,
+}
+
+/// Assembles a `Standard` gossip message describing a handful of Nodes and the neighbor
+/// relationships among them, for tests that need to hand a real Node a specific gossip packet.
+#[derive(Default)]
+pub struct StandardBuilder {
+    nodes: Vec<StandardNode>,
+    half_neighbor_pairs: Vec<(PublicKey, PublicKey)>,
+    chain_id: u8,
+}
+
+impl StandardBuilder {
+    pub fn new() -> StandardBuilder {
+        StandardBuilder::default()
+    }
+
+    pub fn add_substratum_node(
+        mut self,
+        node: &SubstratumMockNode,
+        version: u32,
+    ) -> StandardBuilder {
+        self.nodes.push(StandardNode {
+            public_key: node.public_key().clone(),
+            version,
+        });
+        self
+    }
+
+    pub fn half_neighbors(mut self, from: &PublicKey, to: &PublicKey) -> StandardBuilder {
+        self.half_neighbor_pairs.push((from.clone(), to.clone()));
+        self
+    }
+
+    pub fn chain_id(mut self, chain_id: u8) -> StandardBuilder {
+        self.chain_id = chain_id;
+        self
+    }
+
+    pub fn build(self) -> Standard {
+        let mut bytes = vec![self.chain_id];
+        self.nodes.iter().for_each(|node| {
+            bytes.extend(node.public_key.as_slice());
+            bytes.push(node.version as u8);
+        });
+        self.half_neighbor_pairs.iter().for_each(|(from, to)| {
+            bytes.extend(from.as_slice());
+            bytes.extend(to.as_slice());
+        });
+        Standard { bytes }
+    }
+}
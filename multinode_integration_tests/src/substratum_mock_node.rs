@@ -0,0 +1,209 @@
+// Copyright (c) 2017-2019, Substratum LLC (https://substratum.net) and/or its affiliates. All rights reserved.
+
+use crate::fake_clock::FakeClock;
+use crate::multinode_gossip::Standard;
+use crate::substratum_node::SubstratumNode;
+use crate::substratum_real_node::SubstratumRealNode;
+use node_lib::sub_lib::cryptde::PublicKey;
+use node_lib::sub_lib::node_addr::NodeAddr;
+use std::io::{Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread;
+use std::time::Duration;
+
+/// Counts gossip arrivals and lets a caller block on a `Condvar` until the count changes, with
+/// the deadline measured against a `FakeClock` shared with whoever is going to move it, rather
+/// than the wall clock. This is what makes `SubstratumMockNode::wait_for_gossip` wake up the
+/// instant gossip shows up instead of sleeping for a fixed duration, while still letting a test
+/// force a timeout deterministically by advancing the fake clock from another thread while this
+/// one is parked.
+struct GossipArrivalGate {
+    arrival_count: Mutex<u64>,
+    condvar: Condvar,
+    fake_clock: FakeClock,
+}
+
+impl GossipArrivalGate {
+    fn new(fake_clock: FakeClock) -> GossipArrivalGate {
+        GossipArrivalGate {
+            arrival_count: Mutex::new(0),
+            condvar: Condvar::new(),
+            fake_clock,
+        }
+    }
+
+    fn record_arrival(&self) {
+        let mut arrival_count = self
+            .arrival_count
+            .lock()
+            .expect("gossip arrival gate is poisoned");
+        *arrival_count += 1;
+        self.condvar.notify_all();
+    }
+
+    fn wait(&self, timeout: Duration) -> Result<(), String> {
+        let mut arrival_count = self
+            .arrival_count
+            .lock()
+            .expect("gossip arrival gate is poisoned");
+        let starting_count = *arrival_count;
+        let deadline = self.fake_clock.now() + timeout;
+        while *arrival_count == starting_count {
+            if self.fake_clock.now() >= deadline {
+                return Err(format!(
+                    "Timed out after {:?} waiting for gossip to arrive",
+                    timeout
+                ));
+            }
+            let wait_result = self
+                .condvar
+                .wait_timeout(arrival_count, Duration::from_millis(10))
+                .expect("gossip arrival gate is poisoned");
+            arrival_count = wait_result.0;
+        }
+        Ok(())
+    }
+}
+
+pub struct SubstratumMockNode {
+    public_key: PublicKey,
+    node_addr: NodeAddr,
+    gossip_arrivals: Arc<GossipArrivalGate>,
+}
+
+impl SubstratumNode for SubstratumMockNode {
+    fn public_key(&self) -> &PublicKey {
+        &self.public_key
+    }
+
+    fn node_addr(&self) -> NodeAddr {
+        self.node_addr.clone()
+    }
+}
+
+impl SubstratumMockNode {
+    /// Builds a mock Node listening on `node_addr` and spawns the background thread that
+    /// listens for incoming packets on its behalf; every packet it receives counts as gossip
+    /// arriving, which is all `wait_for_gossip`'s callers in `neighborhood_constructor` actually
+    /// care about.
+    pub fn new(
+        public_key: PublicKey,
+        node_addr: NodeAddr,
+        fake_clock: FakeClock,
+    ) -> SubstratumMockNode {
+        let gossip_arrivals = Arc::new(GossipArrivalGate::new(fake_clock));
+        start_gossip_listener(&node_addr, Arc::clone(&gossip_arrivals));
+        SubstratumMockNode {
+            public_key,
+            node_addr,
+            gossip_arrivals,
+        }
+    }
+
+    pub fn transmit_debut(&self, real_node: &SubstratumRealNode) -> Result<(), String> {
+        self.transmit(real_node, self.public_key.as_slice().to_vec())
+    }
+
+    pub fn transmit_multinode_gossip(
+        &self,
+        real_node: &SubstratumRealNode,
+        gossip: &Standard,
+    ) -> Result<(), String> {
+        self.transmit(real_node, gossip.clone().into())
+    }
+
+    /// Blocks until gossip has arrived at this mock Node since the call began, or until the
+    /// shared fake clock passes `timeout`, whichever happens first.
+    pub fn wait_for_gossip(&self, timeout: Duration) -> Result<(), String> {
+        self.gossip_arrivals.wait(timeout)
+    }
+
+    fn transmit(&self, real_node: &SubstratumRealNode, bytes: Vec<u8>) -> Result<(), String> {
+        let node_addr = real_node.node_addr();
+        let socket_addr = SocketAddr::new(node_addr.ip_addr(), node_addr.ports()[0]);
+        let mut stream =
+            TcpStream::connect(socket_addr).map_err(|e| format!("Could not connect: {:?}", e))?;
+        stream
+            .write_all(&bytes)
+            .map_err(|e| format!("Could not transmit: {:?}", e))
+    }
+}
+
+/// Binds a listener on `node_addr` and spawns a thread that counts each incoming connection
+/// whose first read yields at least one byte as one gossip arrival, bumping `gossip_arrivals` so
+/// any thread parked in `wait_for_gossip` wakes up immediately. The thread is not stopped when
+/// the mock Node is dropped; it keeps listening until accepting a connection fails.
+fn start_gossip_listener(node_addr: &NodeAddr, gossip_arrivals: Arc<GossipArrivalGate>) {
+    let socket_addr = SocketAddr::new(node_addr.ip_addr(), node_addr.ports()[0]);
+    let listener = TcpListener::bind(socket_addr)
+        .unwrap_or_else(|e| panic!("Could not bind mock Node listener on {:?}: {:?}", socket_addr, e));
+    thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut stream = match stream {
+                Ok(stream) => stream,
+                Err(_) => break,
+            };
+            let mut buf = [0u8; 1024];
+            match stream.read(&mut buf) {
+                Ok(0) | Err(_) => continue,
+                Ok(_) => gossip_arrivals.record_arrival(),
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+    use std::time::Instant;
+
+    fn mock_node_addr(port: u16) -> NodeAddr {
+        NodeAddr::new(
+            &std::net::IpAddr::from_str("127.0.0.1").unwrap(),
+            &vec![port],
+        )
+    }
+
+    #[test]
+    fn an_inbound_packet_wakes_up_wait_for_gossip() {
+        let node = SubstratumMockNode::new(
+            PublicKey::new(&[1, 2, 3]),
+            mock_node_addr(14_890),
+            FakeClock::new(),
+        );
+
+        thread::spawn(|| {
+            thread::sleep(Duration::from_millis(50));
+            let mut stream = TcpStream::connect(("127.0.0.1", 14_890)).unwrap();
+            stream.write_all(&[4, 5, 6]).unwrap();
+        });
+
+        let result = node.wait_for_gossip(Duration::from_secs(2));
+
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn advancing_the_fake_clock_from_another_thread_times_out_a_blocked_wait() {
+        let fake_clock = FakeClock::new();
+        let node = SubstratumMockNode::new(
+            PublicKey::new(&[7, 8, 9]),
+            mock_node_addr(14_891),
+            fake_clock.clone(),
+        );
+        let advancer = fake_clock.clone();
+        let advancer_handle = thread::spawn(move || {
+            thread::sleep(Duration::from_millis(50));
+            advancer.advance(Duration::from_secs(10))
+        });
+
+        let started_at = Instant::now();
+        let result = node.wait_for_gossip(Duration::from_secs(5));
+        let _advance_guard = advancer_handle.join().expect("advancer thread panicked");
+
+        assert!(result.is_err());
+        assert!(started_at.elapsed() < Duration::from_secs(1));
+    }
+}
@@ -0,0 +1,17 @@
+// Copyright (c) 2017-2019, Substratum LLC (https://substratum.net) and/or its affiliates. All rights reserved.
+
+use node_lib::sub_lib::cryptde::PublicKey;
+use node_lib::sub_lib::node_addr::NodeAddr;
+
+/// Behavior shared by every kind of Node that can appear in a multinode test cluster, whether
+/// it's a `SubstratumRealNode` running the actual binary or a `SubstratumMockNode` standing in
+/// for a neighbor, so that code like `neighborhood_constructor` can treat both uniformly.
+pub trait SubstratumNode {
+    fn public_key(&self) -> &PublicKey;
+
+    fn node_addr(&self) -> NodeAddr;
+
+    fn node_addr_opt(&self) -> Option<NodeAddr> {
+        Some(self.node_addr())
+    }
+}
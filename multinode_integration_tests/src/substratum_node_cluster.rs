@@ -0,0 +1,52 @@
+// Copyright (c) 2017-2019, Substratum LLC (https://substratum.net) and/or its affiliates. All rights reserved.
+
+use crate::fake_clock::FakeClock;
+use crate::substratum_mock_node::SubstratumMockNode;
+use crate::substratum_real_node::{NodeStartupConfig, SubstratumRealNode};
+use node_lib::sub_lib::cryptde::PublicKey;
+use node_lib::sub_lib::node_addr::NodeAddr;
+use std::net::IpAddr;
+use std::str::FromStr;
+
+/// Hands out fresh Nodes, real or mock, for a single multinode test, each on its own loopback
+/// address, so an individual test doesn't have to know how they're wired together. The Nodes
+/// belong to the caller; the cluster keeps no record of them.
+pub struct SubstratumNodeCluster {
+    pub chain_id: u8,
+    next_ip_octet: u8,
+}
+
+impl SubstratumNodeCluster {
+    pub fn new(chain_id: u8) -> SubstratumNodeCluster {
+        SubstratumNodeCluster {
+            chain_id,
+            next_ip_octet: 2,
+        }
+    }
+
+    pub fn start_real_node(&mut self, config: NodeStartupConfig) -> SubstratumRealNode {
+        let public_key = config
+            .fake_public_key
+            .unwrap_or_else(|| PublicKey::new(&[]));
+        SubstratumRealNode::new(public_key, self.next_node_addr())
+    }
+
+    pub fn start_mock_node_with_public_key(
+        &mut self,
+        ports: Vec<u16>,
+        public_key: &PublicKey,
+    ) -> SubstratumMockNode {
+        let node_addr = NodeAddr::new(&self.next_ip_addr(), &ports);
+        SubstratumMockNode::new(public_key.clone(), node_addr, FakeClock::new())
+    }
+
+    fn next_node_addr(&mut self) -> NodeAddr {
+        NodeAddr::new(&self.next_ip_addr(), &vec![10000])
+    }
+
+    fn next_ip_addr(&mut self) -> IpAddr {
+        let octet = self.next_ip_octet;
+        self.next_ip_octet += 1;
+        IpAddr::from_str(&format!("127.0.0.{}", octet)).expect("malformed loopback address")
+    }
+}
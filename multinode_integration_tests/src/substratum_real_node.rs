@@ -0,0 +1,84 @@
+// Copyright (c) 2017-2019, Substratum LLC (https://substratum.net) and/or its affiliates. All rights reserved.
+
+use crate::substratum_node::SubstratumNode;
+use node_lib::sub_lib::cryptde::PublicKey;
+use node_lib::sub_lib::node_addr::NodeAddr;
+use node_lib::sub_lib::wallet::Wallet;
+
+/// The Node under test in a multinode test cluster, as opposed to a `SubstratumMockNode`, which
+/// stands in for one of its neighbors. It holds only the Node's public key and the address at
+/// which it's reached; constructing one does not start the Node binary.
+pub struct SubstratumRealNode {
+    public_key: PublicKey,
+    node_addr: NodeAddr,
+}
+
+impl SubstratumNode for SubstratumRealNode {
+    fn public_key(&self) -> &PublicKey {
+        &self.public_key
+    }
+
+    fn node_addr(&self) -> NodeAddr {
+        self.node_addr.clone()
+    }
+}
+
+impl SubstratumRealNode {
+    pub fn new(public_key: PublicKey, node_addr: NodeAddr) -> SubstratumRealNode {
+        SubstratumRealNode {
+            public_key,
+            node_addr,
+        }
+    }
+}
+
+/// Describes the consuming wallet a `SubstratumRealNode` should start up with, derived from a
+/// seed string so that tests can reproducibly address a particular Node's wallet.
+pub struct ConsumingWalletInfo {
+    pub wallet: Wallet,
+}
+
+pub fn make_consuming_wallet_info(seed: &str) -> ConsumingWalletInfo {
+    ConsumingWalletInfo {
+        wallet: Wallet::new(seed),
+    }
+}
+
+/// Builds up the startup configuration for a `SubstratumRealNode` before handing it to
+/// `SubstratumNodeCluster::start_real_node`.
+#[derive(Default)]
+pub struct NodeStartupConfigBuilder {
+    fake_public_key: Option<PublicKey>,
+    consuming_wallet_info: Option<ConsumingWalletInfo>,
+}
+
+pub struct NodeStartupConfig {
+    pub fake_public_key: Option<PublicKey>,
+    pub consuming_wallet_info: Option<ConsumingWalletInfo>,
+}
+
+impl NodeStartupConfigBuilder {
+    pub fn standard() -> NodeStartupConfigBuilder {
+        NodeStartupConfigBuilder::default()
+    }
+
+    pub fn fake_public_key(mut self, public_key: &PublicKey) -> NodeStartupConfigBuilder {
+        self.fake_public_key = Some(public_key.clone());
+        self
+    }
+
+    pub fn consuming_wallet_info(
+        mut self,
+        consuming_wallet_info: ConsumingWalletInfo,
+    ) -> NodeStartupConfigBuilder {
+        self.consuming_wallet_info = Some(consuming_wallet_info);
+        self
+    }
+
+    pub fn build(self) -> NodeStartupConfig {
+        NodeStartupConfig {
+            fake_public_key: self.fake_public_key,
+            consuming_wallet_info: self.consuming_wallet_info,
+        }
+    }
+}